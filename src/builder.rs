@@ -0,0 +1,117 @@
+use crate::{
+    entry::Entry,
+    writer::write_pak,
+    PakError,
+    PakResult,
+    MAX_DATA_LEN,
+    MAX_NAME_LEN,
+    XOR_KEY,
+};
+use std::{
+    borrow::Cow,
+    io::{
+        Cursor,
+        Read,
+        Write,
+    },
+    path::Path,
+};
+
+/// Assembles a pakfile from files on disk or arbitrary readers.
+///
+/// Entries are validated against [`MAX_NAME_LEN`] and [`MAX_DATA_LEN`] as they are
+/// added, so a too-long name or file is rejected eagerly rather than at
+/// [`PakBuilder::build_to`] time. The builder finishes by encrypting and writing
+/// the collected entries through [`crate::writer::PakWriter`].
+#[derive(Debug, Default)]
+pub struct PakBuilder {
+    entries: Vec<Entry<'static>>,
+}
+
+impl PakBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an entry at `path_in_pak`, reading its contents from `reader`.
+    pub fn add_file<R: Read>(&mut self, path_in_pak: &str, mut reader: R) -> PakResult<&mut Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        self.push(path_in_pak.to_string(), data)
+    }
+
+    /// Adds a single file from disk, using its file name as the in-pak path and
+    /// deriving the entry's filetime from the file's modification time.
+    pub fn add_path(&mut self, fs_path: &Path) -> PakResult<&mut Self> {
+        let name = fs_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.add_fs_file(fs_path, name)
+    }
+
+    /// Recursively adds every file under `fs_root`, mapping each file's path
+    /// relative to `fs_root` to a backslash-separated in-pak path.
+    pub fn add_dir_recursive(&mut self, fs_root: &Path) -> PakResult<&mut Self> {
+        self.add_dir_inner(fs_root, fs_root)?;
+        Ok(self)
+    }
+
+    fn add_dir_inner(&mut self, fs_root: &Path, dir: &Path) -> PakResult<()> {
+        for child in std::fs::read_dir(dir)? {
+            let child = child?;
+            let path = child.path();
+            if child.file_type()?.is_dir() {
+                self.add_dir_inner(fs_root, &path)?;
+            } else {
+                let rel = path.strip_prefix(fs_root).unwrap_or(&path);
+                let name = pak_path(rel);
+                self.add_fs_file(&path, name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a file from disk and stores it under `name`, keeping its modification time.
+    fn add_fs_file(&mut self, fs_path: &Path, name: String) -> PakResult<&mut Self> {
+        let data = std::fs::read(fs_path)?;
+        self.push(name, data)?;
+        if let Ok(modified) = std::fs::metadata(fs_path).and_then(|m| m.modified()) {
+            self.entries.last_mut().unwrap().set_modified(modified);
+        }
+        Ok(self)
+    }
+
+    /// Validates and stores one entry, encrypting its plaintext contents.
+    fn push(&mut self, name: String, data: Vec<u8>) -> PakResult<&mut Self> {
+        if name.len() > MAX_NAME_LEN {
+            return Err(PakError::InvalidNameLength(name.len()));
+        }
+        if data.len() > MAX_DATA_LEN {
+            return Err(PakError::InvalidDataLength(data.len()));
+        }
+
+        // `Entry` holds bytes in their on-disk (XORed) form and decrypts on read.
+        let encrypted: Vec<u8> = data.iter().map(|b| b ^ XOR_KEY).collect();
+        self.entries.push(Entry {
+            path: name.into(),
+            filetime: 0,
+            data: Cursor::new(Cow::Owned(encrypted)),
+        });
+        Ok(self)
+    }
+
+    /// Encrypts and writes the collected entries as a complete pakfile.
+    pub fn build_to<W: Write>(&mut self, writer: W) -> PakResult<()> {
+        write_pak(writer, &mut self.entries)
+    }
+}
+
+/// Converts an OS path into a backslash-separated in-pak path.
+fn pak_path(rel: &Path) -> String {
+    rel.components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("\\")
+}
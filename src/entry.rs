@@ -0,0 +1,171 @@
+use crate::{
+    PakResult,
+    MS_FILETIME_START_TICKS,
+    PATH_SEPERATOR_BYTESET,
+    TICKS_PER_NANOSECOND,
+    TICKS_PER_SECOND,
+    XOR_KEY,
+};
+use bstr::{
+    BStr,
+    BString,
+    ByteSlice,
+};
+use std::{
+    borrow::Cow,
+    io::{
+        Cursor,
+        Read,
+    },
+    path::Path,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+/// A single file inside a pakfile.
+///
+/// The `data` cursor holds the still-encrypted bytes exactly as they appear on
+/// disk; decryption happens lazily as the entry is [`Read`], so constructing an
+/// `Entry` from a borrowed slice costs nothing until its contents are consumed.
+#[derive(Debug, PartialEq)]
+pub struct Entry<'a> {
+    pub(crate) path: BString,
+    pub(crate) filetime: u64,
+    pub(crate) data: Cursor<Cow<'a, [u8]>>,
+}
+
+impl<'a> Entry<'a> {
+    /// The full, backslash-separated path of this entry inside the pak.
+    pub fn path(&self) -> &BStr {
+        self.path.as_bstr()
+    }
+
+    /// The directory portion of [`Entry::path`], or `None` for a top-level entry.
+    pub fn dir(&self) -> Option<&BStr> {
+        self.path
+            .iter()
+            .rposition(|b| PATH_SEPERATOR_BYTESET.contains(b))
+            .map(|i| self.path[..i].as_bstr())
+    }
+
+    /// The size, in bytes, of this entry's (decrypted) contents.
+    pub fn size(&self) -> usize {
+        self.data.get_ref().len()
+    }
+
+    /// The entry's modification time, decoded from its stored 64-bit Windows FILETIME.
+    ///
+    /// `filetime` counts 100-ns ticks since 1601-01-01; shifting it by the negative
+    /// 1601→1970 offset ([`MS_FILETIME_START_TICKS`]) yields ticks relative to the
+    /// Unix epoch, from which the seconds and nanoseconds are recovered.
+    pub fn modified(&self) -> SystemTime {
+        let unix_ticks = self.filetime as i64 + MS_FILETIME_START_TICKS;
+        // `div_euclid`/`rem_euclid` borrow a second for negative times so the
+        // nanosecond remainder always lands in `[0, 1e9)`.
+        let secs = unix_ticks.div_euclid(TICKS_PER_SECOND);
+        let sub_ticks = unix_ticks.rem_euclid(TICKS_PER_SECOND);
+        let nanos = sub_ticks as u32 * TICKS_PER_NANOSECOND;
+
+        if secs >= 0 {
+            UNIX_EPOCH + Duration::new(secs as u64, nanos)
+        } else {
+            UNIX_EPOCH - Duration::new((-secs) as u64, 0) + Duration::new(0, nanos)
+        }
+    }
+
+    /// Sets the entry's modification time, encoding it back into the stored FILETIME.
+    pub fn set_modified(&mut self, time: SystemTime) {
+        let unix_ticks = match time.duration_since(UNIX_EPOCH) {
+            Ok(d) => (d.as_nanos() / TICKS_PER_NANOSECOND as u128) as i64,
+            Err(e) => -((e.duration().as_nanos() / TICKS_PER_NANOSECOND as u128) as i64),
+        };
+        self.filetime = (unix_ticks - MS_FILETIME_START_TICKS) as u64;
+    }
+
+    /// Takes ownership of this entry's data, returning an `Entry` that borrows nothing.
+    pub fn into_owned(self) -> Entry<'static> {
+        Entry {
+            path: self.path,
+            filetime: self.filetime,
+            data: Cursor::new(Cow::Owned(self.data.into_inner().into_owned())),
+        }
+    }
+}
+
+impl Entry<'_> {
+    /// Decrypts this entry and writes it under `dir`, creating its parent directory first.
+    ///
+    /// `create_dir_all` is idempotent and tolerant of concurrent creation, so parallel
+    /// workers whose entries share a directory can all call it without coordinating.
+    pub(crate) fn extract_into(&mut self, dir: &Path) -> PakResult<()> {
+        if let Some(parent) = self.dir() {
+            let entry_dir = dir.join(parent.to_path_lossy());
+            std::fs::create_dir_all(&entry_dir)?;
+        }
+
+        let entry_path = dir.join(self.path().to_path_lossy());
+        let mut f = std::fs::File::create(&entry_path)?;
+        std::io::copy(self, &mut f)?;
+        Ok(())
+    }
+}
+
+/// The worker count used when none is given: the machine's parallelism, or `1` if unknown.
+pub(crate) fn default_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Extracts `entries` under `dir`, spreading the work across `threads` workers.
+///
+/// Distinct entries are independent — each owns its own cursor and XOR is stateless
+/// per byte — so extraction is partitioned across a scoped thread pool. A `threads`
+/// of `1` (or a single entry) runs serially.
+pub(crate) fn extract_entries(
+    entries: &mut [Entry<'_>],
+    dir: &Path,
+    threads: usize,
+) -> PakResult<()> {
+    std::fs::create_dir_all(dir)?;
+
+    if threads <= 1 || entries.len() <= 1 {
+        for entry in entries.iter_mut() {
+            entry.extract_into(dir)?;
+        }
+        return Ok(());
+    }
+
+    let chunk_size = entries.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| -> PakResult<()> {
+        let handles: Vec<_> = entries
+            .chunks_mut(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || -> PakResult<()> {
+                    for entry in chunk {
+                        entry.extract_into(dir)?;
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap()?;
+        }
+        Ok(())
+    })
+}
+
+impl Read for Entry<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.data.read(buf)?;
+        for b in &mut buf[..n] {
+            *b ^= XOR_KEY;
+        }
+        Ok(n)
+    }
+}
@@ -0,0 +1,247 @@
+//! A C ABI over [`Pak`], compiled only with the `ffi` feature.
+//!
+//! The surface is intentionally small and ctypes-friendly: open a pak into an
+//! opaque handle, query its entries by index, copy an entry's bytes into a
+//! caller-owned buffer using a length-probe convention, and free the handle.
+//! Every fallible call returns a stable integer derived from [`PakError`].
+
+use crate::{
+    Pak,
+    PakError,
+    XOR_KEY,
+};
+use std::{
+    ffi::CStr,
+    io::Cursor,
+    os::raw::c_char,
+    slice,
+};
+
+/// Success.
+pub const PAK_OK: i32 = 0;
+/// An I/O error occurred. Maps to [`PakError::Io`].
+pub const PAK_ERR_IO: i32 = 1;
+/// The file had an invalid magic number. Maps to [`PakError::InvalidMagic`].
+pub const PAK_ERR_INVALID_MAGIC: i32 = 2;
+/// The file had an unsupported version. Maps to [`PakError::InvalidVersion`].
+pub const PAK_ERR_INVALID_VERSION: i32 = 3;
+/// A name exceeded `MAX_NAME_LEN`. Maps to [`PakError::InvalidNameLength`].
+pub const PAK_ERR_INVALID_NAME_LENGTH: i32 = 4;
+/// A file exceeded `MAX_DATA_LEN`. Maps to [`PakError::InvalidDataLength`].
+pub const PAK_ERR_INVALID_DATA_LENGTH: i32 = 5;
+/// A required pointer argument was null.
+pub const PAK_ERR_NULL: i32 = -1;
+/// An index was out of bounds.
+pub const PAK_ERR_OUT_OF_BOUNDS: i32 = -2;
+
+/// An opaque handle to an opened pakfile. Created by `pak_open_*`, released by `pak_free`.
+pub struct PakHandle {
+    pak: Pak<'static>,
+}
+
+/// Maps a [`PakError`] to its stable integer code.
+fn error_code(err: &PakError) -> i32 {
+    match err {
+        PakError::Io(_) => PAK_ERR_IO,
+        PakError::InvalidMagic(_) => PAK_ERR_INVALID_MAGIC,
+        PakError::InvalidVersion(_) => PAK_ERR_INVALID_VERSION,
+        PakError::InvalidNameLength(_) => PAK_ERR_INVALID_NAME_LENGTH,
+        PakError::InvalidDataLength(_) => PAK_ERR_INVALID_DATA_LENGTH,
+    }
+}
+
+/// Opens a pakfile from a NUL-terminated path, storing an owned handle in `out_handle`.
+///
+/// # Safety
+/// `path` must be a valid NUL-terminated string and `out_handle` a valid, writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn pak_open_path(
+    path: *const c_char,
+    out_handle: *mut *mut PakHandle,
+) -> i32 {
+    if path.is_null() || out_handle.is_null() {
+        return PAK_ERR_NULL;
+    }
+
+    let path = match CStr::from_ptr(path).to_str() {
+        Ok(p) => p,
+        Err(_) => return PAK_ERR_IO,
+    };
+
+    let file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(_) => return PAK_ERR_IO,
+    };
+
+    match Pak::from_read(file) {
+        Ok(pak) => {
+            *out_handle = Box::into_raw(Box::new(PakHandle { pak }));
+            PAK_OK
+        }
+        Err(e) => error_code(&e),
+    }
+}
+
+/// Opens a pakfile from a byte buffer, copying it into an owned handle.
+///
+/// # Safety
+/// `data` must point to `len` readable bytes and `out_handle` be valid and writable.
+#[no_mangle]
+pub unsafe extern "C" fn pak_open_buffer(
+    data: *const u8,
+    len: usize,
+    out_handle: *mut *mut PakHandle,
+) -> i32 {
+    if data.is_null() || out_handle.is_null() {
+        return PAK_ERR_NULL;
+    }
+
+    let owned = slice::from_raw_parts(data, len).to_vec();
+    match Pak::from_read(Cursor::new(owned)) {
+        Ok(pak) => {
+            *out_handle = Box::into_raw(Box::new(PakHandle { pak }));
+            PAK_OK
+        }
+        Err(e) => error_code(&e),
+    }
+}
+
+/// Returns the number of entries, or `-1` if `handle` is null.
+///
+/// # Safety
+/// `handle` must be a handle returned by `pak_open_*` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn pak_entry_count(handle: *const PakHandle) -> i64 {
+    match handle.as_ref() {
+        Some(h) => h.pak.entries.len() as i64,
+        None => -1,
+    }
+}
+
+/// Writes the size of entry `index` into `out_size`.
+///
+/// # Safety
+/// `handle` must be a live handle and `out_size` a valid, writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn pak_entry_size(
+    handle: *const PakHandle,
+    index: usize,
+    out_size: *mut u32,
+) -> i32 {
+    let handle = match handle.as_ref() {
+        Some(h) => h,
+        None => return PAK_ERR_NULL,
+    };
+    if out_size.is_null() {
+        return PAK_ERR_NULL;
+    }
+    match handle.pak.entries.get(index) {
+        Some(entry) => {
+            *out_size = entry.size() as u32;
+            PAK_OK
+        }
+        None => PAK_ERR_OUT_OF_BOUNDS,
+    }
+}
+
+/// Writes the raw Windows FILETIME of entry `index` into `out_filetime`.
+///
+/// # Safety
+/// `handle` must be a live handle and `out_filetime` a valid, writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn pak_entry_filetime(
+    handle: *const PakHandle,
+    index: usize,
+    out_filetime: *mut u64,
+) -> i32 {
+    let handle = match handle.as_ref() {
+        Some(h) => h,
+        None => return PAK_ERR_NULL,
+    };
+    if out_filetime.is_null() {
+        return PAK_ERR_NULL;
+    }
+    match handle.pak.entries.get(index) {
+        Some(entry) => {
+            *out_filetime = entry.filetime;
+            PAK_OK
+        }
+        None => PAK_ERR_OUT_OF_BOUNDS,
+    }
+}
+
+/// Copies entry `index`'s name into `buf`, returning the name's length in bytes.
+///
+/// Following the length-probe convention, passing a null or too-small `buf` writes
+/// nothing and just reports the required length; negative values are error codes.
+///
+/// # Safety
+/// `handle` must be a live handle; if non-null, `buf` must be writable for `buf_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pak_entry_name(
+    handle: *const PakHandle,
+    index: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> isize {
+    let handle = match handle.as_ref() {
+        Some(h) => h,
+        None => return PAK_ERR_NULL as isize,
+    };
+    let entry = match handle.pak.entries.get(index) {
+        Some(entry) => entry,
+        None => return PAK_ERR_OUT_OF_BOUNDS as isize,
+    };
+
+    let name = entry.path();
+    let needed = name.len();
+    if !buf.is_null() && buf_len >= needed {
+        slice::from_raw_parts_mut(buf, needed).copy_from_slice(name);
+    }
+    needed as isize
+}
+
+/// Decrypts entry `index` into `buf`, returning the number of bytes the entry holds.
+///
+/// Like [`pak_entry_name`], a null or too-small `buf` copies nothing and only reports
+/// the required length, so callers can probe for the size first; negative values are errors.
+///
+/// # Safety
+/// `handle` must be a live handle; if non-null, `buf` must be writable for `buf_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pak_extract_entry(
+    handle: *const PakHandle,
+    index: usize,
+    buf: *mut u8,
+    buf_len: usize,
+) -> isize {
+    let handle = match handle.as_ref() {
+        Some(h) => h,
+        None => return PAK_ERR_NULL as isize,
+    };
+    let entry = match handle.pak.entries.get(index) {
+        Some(entry) => entry,
+        None => return PAK_ERR_OUT_OF_BOUNDS as isize,
+    };
+
+    let encrypted = entry.data.get_ref();
+    let needed = encrypted.len();
+    if !buf.is_null() && buf_len >= needed {
+        let out = slice::from_raw_parts_mut(buf, needed);
+        for (dst, src) in out.iter_mut().zip(encrypted.iter()) {
+            *dst = src ^ XOR_KEY;
+        }
+    }
+    needed as isize
+}
+
+/// Frees a handle returned by `pak_open_*`. Passing null is a no-op.
+///
+/// # Safety
+/// `handle` must have come from `pak_open_*` and must not be used again after this call.
+#[no_mangle]
+pub unsafe extern "C" fn pak_free(handle: *mut PakHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    entry::{
+        default_threads,
+        extract_entries,
+        Entry,
+    },
+    reader::PakReader,
+    PakError,
+    PakResult,
+    Record,
+};
+use bstr::ByteSlice;
+use std::{
+    borrow::Cow,
+    io::{
+        Cursor,
+        Read,
+        Seek,
+        SeekFrom,
+    },
+    path::Path,
+};
+
+/// Builds the `(PakError::Io(NotFound))` returned when a path isn't in the table.
+fn not_found(path: &str) -> PakError {
+    PakError::Io(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("no entry named '{}'", path),
+    ))
+}
+
+/// Finds the record index whose name equals `path`, losslessly comparing raw bytes.
+fn find(records: &[Record], path: &str) -> Option<usize> {
+    let needle = path.as_bytes().as_bstr();
+    records.iter().position(|record| record.name.as_bstr() == needle)
+}
+
+/// A lazily-decrypting view over a pakfile's record table.
+///
+/// Where [`crate::Pak`] materializes every [`Entry`] up front, a `PakIndex` parses
+/// only the table (names, sizes, filetimes and the computed byte offset of each
+/// body) and leaves the file data untouched. [`PakIndex::open`] can then seek
+/// straight to one entry's slice and decrypt just those bytes, which is what a
+/// tool pulling a single asset out of a large pak wants.
+#[derive(Debug)]
+pub struct PakIndex<'a> {
+    data: &'a [u8],
+    records: Vec<Record>,
+    /// Byte offset into `data` of each record's (encrypted) body, parallel to `records`.
+    offsets: Vec<usize>,
+}
+
+impl<'a> PakIndex<'a> {
+    /// Builds an index over a byte slice, parsing the record table but no file data.
+    pub fn from_bytes(bytes: &'a [u8]) -> PakResult<PakIndex<'a>> {
+        let mut reader = PakReader::new(bytes);
+        reader.read_magic()?;
+        reader.read_version()?;
+
+        let records = reader.read_records()?;
+
+        // The slice handed back by `into_reader` begins at the first file body;
+        // turn that back into an absolute offset within the original `bytes`.
+        let rest = reader.into_reader();
+        let mut offset = bytes.len() - rest.len();
+
+        let mut offsets = Vec::with_capacity(records.len());
+        for record in &records {
+            offsets.push(offset);
+            offset += record.file_size as usize;
+        }
+
+        Ok(PakIndex {
+            data: bytes,
+            records,
+            offsets,
+        })
+    }
+
+    /// Iterates over every record in table order, without touching file data.
+    pub fn entries(&self) -> impl Iterator<Item = &Record> {
+        self.records.iter()
+    }
+
+    /// Extracts every indexed entry to disk under `dir`, in parallel across the machine's cores.
+    ///
+    /// Like [`crate::Pak::extract_to_dir`], but sourced from the index: each entry is
+    /// opened against the backing buffer so only the bytes being written are decrypted.
+    pub fn extract_to_dir(&self, dir: &Path) -> PakResult<()> {
+        self.extract_to_dir_with_threads(dir, default_threads())
+    }
+
+    /// Extracts every indexed entry to disk under `dir` using `threads` workers.
+    pub fn extract_to_dir_with_threads(&self, dir: &Path, threads: usize) -> PakResult<()> {
+        let mut entries: Vec<Entry<'a>> = (0..self.records.len())
+            .map(|index| self.entry_at(index))
+            .collect();
+        extract_entries(&mut entries, dir, threads)
+    }
+
+    /// Builds the [`Entry`] for the record at `index`, borrowing its bytes from the buffer.
+    fn entry_at(&self, index: usize) -> Entry<'a> {
+        let record = &self.records[index];
+        let start = self.offsets[index];
+        let data = &self.data[start..start + record.file_size as usize];
+
+        Entry {
+            path: record.name.clone(),
+            filetime: record.filetime,
+            data: Cursor::new(Cow::Borrowed(data)),
+        }
+    }
+
+    /// Opens a single entry by path, decrypting only its bytes.
+    ///
+    /// The returned [`Entry`] borrows its (still-encrypted) slice from the backing
+    /// buffer, so nothing outside the requested file is copied or decrypted.
+    pub fn open(&self, path: &str) -> PakResult<Entry<'a>> {
+        let index = find(&self.records, path).ok_or_else(|| not_found(path))?;
+        Ok(self.entry_at(index))
+    }
+}
+
+/// A random-access index over a seekable [`Read`] source.
+///
+/// The table is parsed once up front; [`PakStreamIndex::open`] then seeks straight
+/// to one entry's body — skipping over the intervening files using their recorded
+/// sizes — rather than reading the whole pak into memory. Use this over
+/// [`PakIndex`] when the source is a file or other stream instead of a `&[u8]`.
+#[derive(Debug)]
+pub struct PakStreamIndex<R> {
+    reader: R,
+    records: Vec<Record>,
+    /// Absolute byte offset of each record's body in the stream, parallel to `records`.
+    offsets: Vec<u64>,
+}
+
+impl<R: Read + Seek> PakStreamIndex<R> {
+    /// Reads the record table from `reader`, leaving the file bodies in place.
+    pub fn from_read(mut reader: R) -> PakResult<Self> {
+        let records = {
+            let mut pak = PakReader::new(&mut reader);
+            pak.read_magic()?;
+            pak.read_version()?;
+            pak.read_records()?
+        };
+
+        // The table has been consumed, so the stream now sits at the first body.
+        let mut offset = reader.stream_position()?;
+        let mut offsets = Vec::with_capacity(records.len());
+        for record in &records {
+            offsets.push(offset);
+            offset += record.file_size as u64;
+        }
+
+        Ok(Self {
+            reader,
+            records,
+            offsets,
+        })
+    }
+
+    /// Iterates over every record in table order, without touching file data.
+    pub fn entries(&self) -> impl Iterator<Item = &Record> {
+        self.records.iter()
+    }
+
+    /// Opens a single entry by path, seeking to and reading only its bytes.
+    pub fn open(&mut self, path: &str) -> PakResult<Entry<'static>> {
+        let index = find(&self.records, path).ok_or_else(|| not_found(path))?;
+
+        let record = &self.records[index];
+        let size = record.file_size as usize;
+        self.reader.seek(SeekFrom::Start(self.offsets[index]))?;
+
+        let mut data = vec![0u8; size];
+        self.reader.read_exact(&mut data)?;
+
+        Ok(Entry {
+            path: record.name.clone(),
+            filetime: record.filetime,
+            data: Cursor::new(Cow::Owned(data)),
+        })
+    }
+}
@@ -1,13 +1,28 @@
+/// Pak builder impl
+pub mod builder;
 /// Pak Entry impl
 pub mod entry;
+/// C FFI surface, enabled by the `ffi` feature
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Lazy random-access index impl
+pub mod index;
 /// Pak impl
 pub mod pak;
 pub(crate) mod reader;
+/// Directory-tree view impl
+pub mod tree;
 pub(crate) mod writer;
 
 pub use crate::{
+    builder::PakBuilder,
     entry::Entry,
+    index::{
+        PakIndex,
+        PakStreamIndex,
+    },
     pak::Pak,
+    tree::PakTree,
 };
 use bstr::BString;
 
@@ -22,6 +37,10 @@ pub const VERSION: &[u8] = &[0; 4];
 
 const FILEFLAGS_END: u8 = 0x80;
 
+/// The byte every part of a pakfile is XORed with. Applied uniformly to the
+/// header, record table and file bodies.
+pub(crate) const XOR_KEY: u8 = 0xf7;
+
 const TICKS_PER_SECOND: i64 = 10_000_000;
 const TICKS_PER_NANOSECOND: u32 = 100;
 const MS_FILETIME_START_SECS: i64 = -11_644_473_600;
@@ -55,10 +74,15 @@ impl From<std::io::Error> for PakError {
     }
 }
 
-#[derive(Debug)]
-struct Record {
+/// A single entry in a pakfile's record table: its name, size and Windows filetime.
+/// Unlike [`Entry`], a `Record` carries no file data — it is the metadata half of an entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The backslash-separated path of the entry.
     pub name: BString,
+    /// The size, in bytes, of the entry's contents.
     pub file_size: u32,
+    /// The entry's modification time, as a 64-bit Windows FILETIME.
     pub filetime: u64,
 }
 
@@ -123,4 +147,112 @@ mod tests {
         let pak2 = Pak::from_read(std::io::Cursor::new(&round)).unwrap();
         assert_eq!(pak, pak2);
     }
+
+    /// An `Entry` with no data, for exercising metadata in isolation.
+    fn empty_entry() -> Entry<'static> {
+        Entry {
+            path: "t".into(),
+            filetime: 0,
+            data: std::io::Cursor::new(std::borrow::Cow::Borrowed(&[][..])),
+        }
+    }
+
+    #[test]
+    fn filetime_round_trip() {
+        use std::time::{
+            Duration,
+            UNIX_EPOCH,
+        };
+
+        let mut entry = empty_entry();
+
+        // A post-1970 time with a 100-ns-aligned fractional part survives exactly.
+        let post = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_700);
+        entry.set_modified(post);
+        assert_eq!(entry.modified(), post);
+
+        // A pre-1970 time exercises the negative-tick borrow and `UNIX_EPOCH - Duration` branch.
+        let pre = UNIX_EPOCH - Duration::new(1_000_000, 500_000_000);
+        entry.set_modified(pre);
+        assert_eq!(entry.modified(), pre);
+    }
+
+    /// Reads an entry's decrypted contents in full.
+    fn read_all(entry: &mut Entry) -> Vec<u8> {
+        let mut out = Vec::new();
+        std::io::Read::read_to_end(entry, &mut out).unwrap();
+        out
+    }
+
+    /// Packs two files with the builder and returns the encoded pakfile bytes; the tests
+    /// below read them back, pinning the `push` encrypt / `Entry::read` decrypt / `PakWriter`
+    /// re-encrypt round-trip.
+    fn sample_pak() -> Vec<u8> {
+        let mut builder = PakBuilder::new();
+        builder.add_file("dir\\a.txt", &b"hello"[..]).unwrap();
+        builder.add_file("b.bin", &[0x00u8, 0x01, 0xff, 0x7f][..]).unwrap();
+
+        let mut out = Vec::new();
+        builder.build_to(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn builder_round_trip() {
+        let bytes = sample_pak();
+        let mut pak = Pak::from_bytes(&bytes).unwrap();
+
+        assert_eq!(pak.entries.len(), 2);
+        assert_eq!(&pak.entries[0].path()[..], &b"dir\\a.txt"[..]);
+        assert_eq!(read_all(&mut pak.entries[0]), b"hello".to_vec());
+        assert_eq!(read_all(&mut pak.entries[1]), vec![0x00, 0x01, 0xff, 0x7f]);
+    }
+
+    #[test]
+    fn index_opens_correct_offsets() {
+        let bytes = sample_pak();
+        let index = PakIndex::from_bytes(&bytes).unwrap();
+
+        assert_eq!(index.entries().count(), 2);
+        assert_eq!(read_all(&mut index.open("dir\\a.txt").unwrap()), b"hello".to_vec());
+        assert_eq!(read_all(&mut index.open("b.bin").unwrap()), vec![0x00, 0x01, 0xff, 0x7f]);
+        assert!(index.open("missing").is_err());
+    }
+
+    #[test]
+    fn stream_index_skips_to_body() {
+        let bytes = sample_pak();
+        let mut index = PakStreamIndex::from_read(std::io::Cursor::new(&bytes)).unwrap();
+
+        assert_eq!(index.entries().count(), 2);
+        // Opening the second entry must seek past the first body, not read it.
+        assert_eq!(read_all(&mut index.open("b.bin").unwrap()), vec![0x00, 0x01, 0xff, 0x7f]);
+        assert_eq!(read_all(&mut index.open("dir\\a.txt").unwrap()), b"hello".to_vec());
+    }
+
+    #[test]
+    fn tree_lookup_and_read_dir() {
+        let bytes = sample_pak();
+        let pak = Pak::from_bytes(&bytes).unwrap();
+        let tree = pak.tree();
+
+        // `get` accepts either separator style.
+        assert!(tree.get("dir\\a.txt").is_some());
+        assert!(tree.get("dir/a.txt").is_some());
+        assert!(tree.get("nope").is_none());
+
+        // The root lists the `dir` directory and the `b.bin` file.
+        let mut root: Vec<_> = tree
+            .read_dir("")
+            .map(|c| (c.name.clone(), c.is_dir()))
+            .collect();
+        root.sort();
+        assert_eq!(
+            root,
+            vec![("b.bin".to_string(), false), ("dir".to_string(), true)]
+        );
+
+        let children: Vec<_> = tree.read_dir("dir").map(|c| c.name.clone()).collect();
+        assert_eq!(children, vec!["a.txt".to_string()]);
+    }
 }
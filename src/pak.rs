@@ -1,16 +1,14 @@
 use crate::{
-    entry::Entry,
+    entry::{
+        default_threads,
+        extract_entries,
+        Entry,
+    },
     reader::PakReader,
-    writer::PakWriter,
+    tree::PakTree,
+    writer::write_pak,
     PakError,
     PakResult,
-    FILEFLAGS_END,
-    MAGIC,
-    VERSION,
-};
-use byteorder::{
-    WriteBytesExt,
-    LE,
 };
 use std::{
     convert::TryInto,
@@ -19,6 +17,7 @@ use std::{
         Read,
         Write,
     },
+    path::Path,
 };
 
 /// An In-memory pakfile. It may reference borrowed data to avoid decrypting the entire file in memory all at once.
@@ -93,29 +92,28 @@ impl<'a> Pak<'a> {
         Pak { entries }
     }
 
-    /// Writes data to a writeable destination. This takes `&mut self` because at the end of this function, all files' cursors will be at the end of the stream.
-    pub fn write_to<W: Write>(&mut self, writer: W) -> PakResult<()> {
-        let mut writer = PakWriter::new(writer);
-        writer.write_all(MAGIC)?;
-        writer.write_all(VERSION)?;
-
-        for entry in self.entries.iter() {
-            writer.write_u8(0x00)?;
-            writer.write_filename(entry.path.as_slice().into())?;
-            writer.write_u32::<LE>(
-                entry
-                    .size()
-                    .try_into()
-                    .map_err(|_| PakError::InvalidDataLength(entry.size()))?,
-            )?;
-            writer.write_u64::<LE>(entry.filetime)?;
-        }
-        writer.write_u8(FILEFLAGS_END)?;
+    /// Returns a filesystem-like [`PakTree`] view over this pak's entries.
+    pub fn tree(&self) -> PakTree<'_, 'a> {
+        PakTree::new(&self.entries)
+    }
 
-        for entry in self.entries.iter_mut() {
-            std::io::copy(entry, &mut writer)?;
-        }
+    /// Extracts every entry to disk under `dir`, in parallel across the machine's cores.
+    ///
+    /// Convenience wrapper over [`Pak::extract_to_dir_with_threads`] using
+    /// [`std::thread::available_parallelism`] for the worker count.
+    pub fn extract_to_dir(&mut self, dir: &Path) -> PakResult<()> {
+        self.extract_to_dir_with_threads(dir, default_threads())
+    }
+
+    /// Extracts every entry to disk under `dir` using `threads` workers.
+    ///
+    /// Entries are partitioned across a scoped thread pool; passing `1` extracts serially.
+    pub fn extract_to_dir_with_threads(&mut self, dir: &Path, threads: usize) -> PakResult<()> {
+        extract_entries(&mut self.entries, dir, threads)
+    }
 
-        Ok(())
+    /// Writes data to a writeable destination. This takes `&mut self` because at the end of this function, all files' cursors will be at the end of the stream.
+    pub fn write_to<W: Write>(&mut self, writer: W) -> PakResult<()> {
+        write_pak(writer, &mut self.entries)
     }
 }
@@ -0,0 +1,111 @@
+use crate::{
+    PakError,
+    PakResult,
+    Record,
+    FILEFLAGS_END,
+    MAGIC,
+    VERSION,
+    XOR_KEY,
+};
+use std::io::Read;
+
+/// Reads and decrypts the structural parts of a pakfile.
+///
+/// Only the header and the record table are decrypted here; file bodies are left
+/// encrypted and handed back verbatim so an [`crate::Entry`] can decrypt them lazily.
+pub(crate) struct PakReader<R> {
+    inner: R,
+}
+
+impl<R: Read> PakReader<R> {
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    fn read_u8_dec(&mut self) -> std::io::Result<u8> {
+        let mut buf = [0u8; 1];
+        self.inner.read_exact(&mut buf)?;
+        Ok(buf[0] ^ XOR_KEY)
+    }
+
+    fn read_u32_dec(&mut self) -> std::io::Result<u32> {
+        let mut buf = [0u8; 4];
+        self.inner.read_exact(&mut buf)?;
+        for b in &mut buf {
+            *b ^= XOR_KEY;
+        }
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_u64_dec(&mut self) -> std::io::Result<u64> {
+        let mut buf = [0u8; 8];
+        self.inner.read_exact(&mut buf)?;
+        for b in &mut buf {
+            *b ^= XOR_KEY;
+        }
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    pub fn read_magic(&mut self) -> PakResult<()> {
+        let mut buf = [0u8; 4];
+        self.inner.read_exact(&mut buf)?;
+        for b in &mut buf {
+            *b ^= XOR_KEY;
+        }
+        if &buf[..] != MAGIC {
+            return Err(PakError::InvalidMagic(buf));
+        }
+        Ok(())
+    }
+
+    pub fn read_version(&mut self) -> PakResult<()> {
+        let mut buf = [0u8; 4];
+        self.inner.read_exact(&mut buf)?;
+        for b in &mut buf {
+            *b ^= XOR_KEY;
+        }
+        if &buf[..] != VERSION {
+            return Err(PakError::InvalidVersion(buf));
+        }
+        Ok(())
+    }
+
+    /// Reads the record table, stopping at the end-of-table flag.
+    pub fn read_records(&mut self) -> PakResult<Vec<Record>> {
+        let mut records = Vec::new();
+        loop {
+            let flag = self.read_u8_dec()?;
+            if flag == FILEFLAGS_END {
+                break;
+            }
+
+            let name_len = self.read_u8_dec()? as usize;
+            let mut name = vec![0u8; name_len];
+            self.inner.read_exact(&mut name)?;
+            for b in &mut name {
+                *b ^= XOR_KEY;
+            }
+
+            let file_size = self.read_u32_dec()?;
+            let filetime = self.read_u64_dec()?;
+
+            records.push(Record {
+                name: name.into(),
+                file_size,
+                filetime,
+            });
+        }
+        Ok(records)
+    }
+
+    /// Consumes the reader, returning the underlying source positioned at the first file body.
+    pub fn into_reader(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for PakReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
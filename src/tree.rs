@@ -0,0 +1,150 @@
+use crate::{
+    entry::Entry,
+    PATH_SEPERATOR_BYTESET,
+};
+use bstr::ByteSlice;
+use std::collections::HashMap;
+
+/// Whether a [`DirEntry`] names a file or a subdirectory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    /// A leaf: a real [`Entry`] in the backing pak.
+    File,
+    /// An interior node implied by one or more entry paths.
+    Directory,
+}
+
+/// One immediate child yielded by [`PakTree::read_dir`] or [`PakTree::walk`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirEntry {
+    /// The child's own name, without any parent components.
+    pub name: String,
+    /// The child's full, forward-slash-normalized path from the pak root.
+    pub path: String,
+    /// Whether this child is a file or a directory.
+    pub kind: NodeKind,
+}
+
+impl DirEntry {
+    /// Whether this child is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.kind == NodeKind::Directory
+    }
+
+    /// Whether this child is a file.
+    pub fn is_file(&self) -> bool {
+        self.kind == NodeKind::File
+    }
+}
+
+/// A filesystem-like view layered over a pak's flat [`Entry`] list.
+///
+/// PopCap paks store backslash-separated paths in a single `Vec`; `PakTree` indexes
+/// those paths so callers can browse them like directories without changing the
+/// on-disk format. The backing entries remain the source of truth — the tree only
+/// borrows them.
+#[derive(Debug)]
+pub struct PakTree<'e, 'a> {
+    entries: &'e [Entry<'a>],
+    /// Normalized full path -> index into `entries`.
+    files: HashMap<String, usize>,
+    /// Normalized directory path ("" is the root) -> its immediate children.
+    children: HashMap<String, Vec<DirEntry>>,
+}
+
+impl<'e, 'a> PakTree<'e, 'a> {
+    /// Builds a tree view over `entries`, normalizing path separators to `/`.
+    pub fn new(entries: &'e [Entry<'a>]) -> Self {
+        let mut files = HashMap::new();
+        let mut children: HashMap<String, Vec<DirEntry>> = HashMap::new();
+
+        for (idx, entry) in entries.iter().enumerate() {
+            let normalized = normalize(entry.path());
+            files.insert(normalized.clone(), idx);
+
+            let parts: Vec<&str> = normalized.split('/').filter(|p| !p.is_empty()).collect();
+            let mut parent = String::new();
+            for (i, part) in parts.iter().enumerate() {
+                let full = if parent.is_empty() {
+                    (*part).to_string()
+                } else {
+                    format!("{}/{}", parent, part)
+                };
+                let kind = if i == parts.len() - 1 {
+                    NodeKind::File
+                } else {
+                    NodeKind::Directory
+                };
+
+                let siblings = children.entry(parent.clone()).or_default();
+                if !siblings.iter().any(|c| c.name == *part) {
+                    siblings.push(DirEntry {
+                        name: (*part).to_string(),
+                        path: full.clone(),
+                        kind,
+                    });
+                }
+
+                parent = full;
+            }
+        }
+
+        Self {
+            entries,
+            files,
+            children,
+        }
+    }
+
+    /// Looks up a single entry by path, accepting either separator style.
+    pub fn get(&self, path: &str) -> Option<&'e Entry<'a>> {
+        let key = normalize_query(path);
+        self.files.get(&key).map(|&idx| &self.entries[idx])
+    }
+
+    /// Iterates over the immediate children of `path` ("" or "/" for the root).
+    pub fn read_dir(&self, path: &str) -> impl Iterator<Item = &DirEntry> {
+        let key = normalize_query(path);
+        self.children.get(&key).into_iter().flatten()
+    }
+
+    /// Recursively walks `path` and everything beneath it, depth-first.
+    pub fn walk(&self, path: &str) -> impl Iterator<Item = &DirEntry> {
+        let mut out = Vec::new();
+        let mut stack: Vec<String> = vec![normalize_query(path)];
+        while let Some(dir) = stack.pop() {
+            if let Some(children) = self.children.get(&dir) {
+                for child in children {
+                    out.push(child);
+                    if child.kind == NodeKind::Directory {
+                        stack.push(child.path.clone());
+                    }
+                }
+            }
+        }
+        out.into_iter()
+    }
+}
+
+/// Normalizes an entry path: every separator byte becomes `/` and leading/trailing
+/// separators are trimmed, matching [`normalize_query`] so the `files` and `children`
+/// maps agree on their keys.
+fn normalize(path: &bstr::BStr) -> String {
+    normalize_query(&path.to_str_lossy())
+}
+
+/// Normalizes a caller-supplied path for lookup: separators become `/` and any
+/// leading or trailing `/` is trimmed so `""`, `"/"` and `"foo/"` all resolve sanely.
+fn normalize_query(path: &str) -> String {
+    let replaced: String = path
+        .chars()
+        .map(|c| {
+            if PATH_SEPERATOR_BYTESET.contains(&(c as u8)) && c.is_ascii() {
+                '/'
+            } else {
+                c
+            }
+        })
+        .collect();
+    replaced.trim_matches('/').to_string()
+}
@@ -0,0 +1,88 @@
+use crate::{
+    entry::Entry,
+    PakError,
+    PakResult,
+    FILEFLAGS_END,
+    MAGIC,
+    MAX_NAME_LEN,
+    VERSION,
+    XOR_KEY,
+};
+use byteorder::{
+    WriteBytesExt,
+    LE,
+};
+use bstr::BStr;
+use std::{
+    convert::TryInto,
+    io::Write,
+};
+
+/// Encrypts everything written through it, mirroring [`crate::reader::PakReader`].
+///
+/// The whole pak stream is XORed with [`XOR_KEY`], so callers write plaintext
+/// bytes (magic, version, record table and file bodies) and this wrapper produces
+/// the on-disk ciphertext.
+pub(crate) struct PakWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> PakWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Writes a length-prefixed filename, validating it against [`MAX_NAME_LEN`].
+    pub fn write_filename(&mut self, name: &BStr) -> PakResult<()> {
+        let len = name.len();
+        if len > MAX_NAME_LEN {
+            return Err(PakError::InvalidNameLength(len));
+        }
+        self.write_u8(len as u8)?;
+        let bytes: &[u8] = name;
+        self.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+/// Serializes `entries` as a complete pakfile: magic, version, record table, then bodies.
+///
+/// Shared by [`crate::Pak::write_to`] and [`crate::builder::PakBuilder::build_to`] so the
+/// on-disk layout lives in exactly one place. Takes `&mut` because each entry's cursor is
+/// consumed while copying its bytes.
+pub(crate) fn write_pak<W: Write>(writer: W, entries: &mut [Entry<'_>]) -> PakResult<()> {
+    let mut writer = PakWriter::new(writer);
+    writer.write_all(MAGIC)?;
+    writer.write_all(VERSION)?;
+
+    for entry in entries.iter() {
+        writer.write_u8(0x00)?;
+        writer.write_filename(entry.path.as_slice().into())?;
+        writer.write_u32::<LE>(
+            entry
+                .size()
+                .try_into()
+                .map_err(|_| PakError::InvalidDataLength(entry.size()))?,
+        )?;
+        writer.write_u64::<LE>(entry.filetime)?;
+    }
+    writer.write_u8(FILEFLAGS_END)?;
+
+    for entry in entries.iter_mut() {
+        std::io::copy(entry, &mut writer)?;
+    }
+
+    Ok(())
+}
+
+impl<W: Write> Write for PakWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let enc: Vec<u8> = buf.iter().map(|b| b ^ XOR_KEY).collect();
+        self.inner.write_all(&enc)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}